@@ -1,33 +1,63 @@
 pub trait Element {
     fn zero() -> Self;
+    fn one() -> Self;
 }
 
 impl Element for usize {
     fn zero() -> Self {
         0
     }
+    fn one() -> Self {
+        1
+    }
 }
 
 impl Element for f64 {
     fn zero() -> Self {
         0.0
     }
+    fn one() -> Self {
+        1.0
+    }
 }
 
 impl Element for u8 {
     fn zero() -> Self {
         0
     }
+    fn one() -> Self {
+        1
+    }
 }
 
 impl Element for u32 {
     fn zero() -> Self {
         0
     }
+    fn one() -> Self {
+        1
+    }
 }
 
 impl Element for i32 {
     fn zero() -> Self {
         0
     }
+    fn one() -> Self {
+        1
+    }
+}
+
+// marker for element types with exact division (so Gaussian elimination's
+// pivot-magnitude tolerance actually means something); integer `Element`
+// impls don't get this, since integer division truncates mid-elimination
+pub trait Field: Element {
+    // magnitude below which a pivot is treated as zero (singular)
+    fn epsilon() -> Self;
+}
+
+impl Field for f64 {
+    fn epsilon() -> Self {
+        1e-9
+    }
 }
@@ -6,9 +6,12 @@ use std::iter::zip;
 use std::ops::AddAssign;
 use std::ops::Div;
 use std::ops::Range;
+use std::ops::Sub;
 use std::ops::{Add, Mul};
 
-use math_utils::{Exp, Pow};
+use element::{Element, Field};
+use math_utils::{Exp, Max, Pow};
+pub mod element;
 pub mod math_utils;
 
 #[derive(Debug, PartialEq, Clone)]
@@ -44,6 +47,8 @@ impl<T: Display> Display for Matrix<T> {
 #[derive(Debug, PartialEq)]
 pub enum MatrixError {
     DimMismatch((usize, usize), (usize, usize)),
+    NotSquare((usize, usize)),
+    Singular,
 }
 
 impl Error for MatrixError {}
@@ -58,6 +63,16 @@ impl Display for MatrixError {
                     a.0, a.1, b.0, b.1
                 )
             }
+            MatrixError::NotSquare(shape) => {
+                write!(
+                    f,
+                    "Error: Matrix with shape ({},{}) is not square",
+                    shape.0, shape.1
+                )
+            }
+            MatrixError::Singular => {
+                write!(f, "Error: Matrix is singular and cannot be inverted")
+            }
         }
     }
 }
@@ -128,6 +143,19 @@ impl<T> Matrix<T> {
     }
 }
 
+impl<T> std::ops::Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+    fn index(&self, idxs: (usize, usize)) -> &T {
+        self.at(idxs).expect("matrix index out of bounds")
+    }
+}
+
+impl<T> std::ops::IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, idxs: (usize, usize)) -> &mut T {
+        self.at_mut(idxs).expect("matrix index out of bounds")
+    }
+}
+
 impl<T> Iterator for Matrix<T> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
@@ -149,6 +177,36 @@ impl<T> Iterator for Matrix<T> {
     }
 }
 
+impl<T> Matrix<T> {
+    // row-major iterator over &T, leaving the matrix intact (unlike the
+    // destructive Iterator impl above, which drains it)
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.values.iter().flat_map(|row| row.iter())
+    }
+
+    // iterator over rows, each itself an iterator over &T
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        self.values.iter().map(|row| row.iter())
+    }
+
+    // iterator over columns, each itself an iterator over &T, walking
+    // values[*][i] for fixed i across rows
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.ncols).map(move |i| self.values.iter().map(move |row| &row[i]))
+    }
+
+    // row-major iterator over ((row, col), &T); named `indexed` rather than
+    // `enumerate` so it doesn't clash with the consuming Iterator::enumerate
+    // already available on Matrix<T> via the impl above (which a by-value
+    // `m.enumerate()` call would resolve to first)
+    pub fn indexed(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.values
+            .iter()
+            .enumerate()
+            .flat_map(|(j, row)| row.iter().enumerate().map(move |(i, el)| ((j, i), el)))
+    }
+}
+
 impl<T: Exp + Clone> Exp for Matrix<T> {
     fn exp(mut self) -> Matrix<T> {
         for row in self.values.iter_mut() {
@@ -160,10 +218,69 @@ impl<T: Exp + Clone> Exp for Matrix<T> {
     }
 }
 
-impl<T: Exp + Clone + AddAssign + Add<Output = T> + Div<Output = T>> Matrix<T> {
+impl<T: Max + Clone> Matrix<T> {
+    pub fn dim_max(&self, dim: usize) -> Matrix<T> {
+        let mut res = Vec::new();
+        if dim == 0 {
+            // column-wise max
+            for i in 0..self.shape().1 {
+                let mut column_max = self.at((0, i)).unwrap().clone();
+                for j in 1..self.shape().0 {
+                    column_max = column_max.max(self.at((j, i)).unwrap().clone());
+                }
+                res.push(column_max);
+            }
+            return Self::from_vecs(vec![res]);
+        }
+        if dim == 1 {
+            // row-wise max
+            for j in 0..self.shape().0 {
+                let mut row_max = self.at((j, 0)).unwrap().clone();
+                for i in 1..self.shape().1 {
+                    row_max = row_max.max(self.at((j, i)).unwrap().clone());
+                }
+                res.push(row_max);
+            }
+            return Self::from_vecs(vec![res]).transpose();
+        }
+        panic!("Only 2D matricies are supported. dim_max dim must be 0 or 1.")
+    }
+}
+
+// `softmax` additionally requires `Sub` (to shift by the per-dimension max)
+// and `Max` (to find it), on top of the `Exp`/`Add`/`Div` it already needed.
+impl<T: Exp + Clone + AddAssign + Add<Output = T> + Sub<Output = T> + Div<Output = T> + Max>
+    Matrix<T>
+{
+    // subtracts the per-dimension max before exponentiating so large inputs
+    // stay finite; softmax(x) == softmax(x - c) along the reduction axis, so
+    // the result is unchanged mathematically
     pub fn softmax(&self, dim: usize) -> Matrix<T> {
         let mut res = self.clone();
-        let e = self.clone().exp();
+        let mut shifted = self.clone();
+        if dim == 0 {
+            // shift each column by its max
+            let maxes = self.dim_max(0);
+            for j in 0..self.shape().0 {
+                for i in 0..self.shape().1 {
+                    *shifted.at_mut((j, i)).unwrap() =
+                        self.at((j, i)).unwrap().clone() - maxes.at((0, i)).unwrap().clone();
+                }
+            }
+        } else if dim == 1 {
+            // shift each row by its max
+            let maxes = self.dim_max(1);
+            for j in 0..self.shape().0 {
+                for i in 0..self.shape().1 {
+                    *shifted.at_mut((j, i)).unwrap() =
+                        self.at((j, i)).unwrap().clone() - maxes.at((j, 0)).unwrap().clone();
+                }
+            }
+        } else {
+            panic!("Only 2D matricies are supported. softmax dim must be 0 or 1.")
+        }
+
+        let e = shifted.exp();
         if dim == 0 {
             // find column-wise sums
             let sums = e.dim_sum(0);
@@ -173,7 +290,7 @@ impl<T: Exp + Clone + AddAssign + Add<Output = T> + Div<Output = T>> Matrix<T> {
                         e.at((j, i)).unwrap().clone() / sums.at((0, i)).unwrap().clone()
                 }
             }
-        } else if dim == 1 {
+        } else {
             // find row-wise sums
             let sums = e.dim_sum(1);
             for j in 0..self.shape().0 {
@@ -182,8 +299,6 @@ impl<T: Exp + Clone + AddAssign + Add<Output = T> + Div<Output = T>> Matrix<T> {
                         e.at((j, i)).unwrap().clone() / sums.at((j, 0)).unwrap().clone()
                 }
             }
-        } else {
-            panic!("Only 2D matricies are supported. softmax dim must be 0 or 1.")
         }
         res
     }
@@ -273,6 +388,225 @@ impl<T: Clone + Mul<Output = T> + AddAssign> Matrix<T> {
     }
 }
 
+fn abs<T: Element + PartialOrd + Sub<Output = T>>(val: T) -> T {
+    if val < T::zero() {
+        T::zero() - val
+    } else {
+        val
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    // submatrix formed by deleting `row` and `col`
+    pub fn minor(&self, row: usize, col: usize) -> Matrix<T> {
+        assert!(
+            row < self.nrows && col < self.ncols,
+            "minor index out of bounds"
+        );
+        let mut res = VecDeque::new();
+        for (j, r) in self.values.iter().enumerate() {
+            if j == row {
+                continue;
+            }
+            let mut res_row = VecDeque::new();
+            for (i, el) in r.iter().enumerate() {
+                if i == col {
+                    continue;
+                }
+                res_row.push_back(el.clone());
+            }
+            res.push_back(res_row);
+        }
+        Matrix::new(res)
+    }
+}
+
+// `determinant`/`cofactor`/`inverse` are bounded by `Field`, not the weaker
+// `Element`: they rely on `T::epsilon()` to detect a zero pivot, and that
+// tolerance is only meaningful for types with exact division (`f64`).
+// Integer `Element` impls don't implement `Field`, so `Matrix::<i32>::inverse`
+// etc. simply don't compile instead of panicking or silently truncating.
+impl<T> Matrix<T>
+where
+    T: Clone + PartialEq + PartialOrd + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Field,
+{
+    // determinant via Gaussian elimination to upper-triangular form: the
+    // product of the pivots, times -1 per row swap used to pick the
+    // largest-magnitude pivot
+    pub fn determinant(&self) -> Result<T, MatrixError> {
+        if self.nrows != self.ncols {
+            return Err(MatrixError::NotSquare(self.shape()));
+        }
+        let n = self.nrows;
+        let mut a = self.clone();
+        let mut det = T::one();
+        let mut sign = T::one();
+        for k in 0..n {
+            let mut pivot_row = k;
+            let mut pivot_mag = abs(a.at((k, k)).unwrap().clone());
+            for j in (k + 1)..n {
+                let mag = abs(a.at((j, k)).unwrap().clone());
+                if mag > pivot_mag {
+                    pivot_row = j;
+                    pivot_mag = mag;
+                }
+            }
+            if pivot_row != k {
+                a.values.swap(k, pivot_row);
+                sign = T::zero() - sign;
+            }
+            if pivot_mag < T::epsilon() {
+                return Ok(T::zero());
+            }
+            let pivot = a.at((k, k)).unwrap().clone();
+            det = det * pivot.clone();
+            for j in (k + 1)..n {
+                let factor = a.at((j, k)).unwrap().clone() / pivot.clone();
+                for i in k..n {
+                    let sub = a.at((k, i)).unwrap().clone() * factor.clone();
+                    let cell = a.at_mut((j, i)).unwrap();
+                    *cell = cell.clone() - sub;
+                }
+            }
+        }
+        Ok(sign * det)
+    }
+
+    // (row, col) cofactor: determinant of the corresponding minor, signed by (-1)^(row + col)
+    pub fn cofactor(&self, row: usize, col: usize) -> Result<T, MatrixError> {
+        let det = self.minor(row, col).determinant()?;
+        if (row + col) % 2 == 0 {
+            Ok(det)
+        } else {
+            Ok(T::zero() - det)
+        }
+    }
+
+    // inverse via Gauss-Jordan elimination on [A | I]: pivot on the
+    // largest-magnitude entry at or below the diagonal in each column, scale
+    // the pivot row to 1, then eliminate that column from every other row
+    pub fn inverse(&self) -> Result<Matrix<T>, MatrixError> {
+        if self.nrows != self.ncols {
+            return Err(MatrixError::NotSquare(self.shape()));
+        }
+        let n = self.nrows;
+        let mut a = self.clone();
+        let mut inv = Matrix::fill((n, n), T::zero());
+        for i in 0..n {
+            *inv.at_mut((i, i)).unwrap() = T::one();
+        }
+        for k in 0..n {
+            let mut pivot_row = k;
+            let mut pivot_mag = abs(a.at((k, k)).unwrap().clone());
+            for j in (k + 1)..n {
+                let mag = abs(a.at((j, k)).unwrap().clone());
+                if mag > pivot_mag {
+                    pivot_row = j;
+                    pivot_mag = mag;
+                }
+            }
+            if pivot_row != k {
+                a.values.swap(k, pivot_row);
+                inv.values.swap(k, pivot_row);
+            }
+            if pivot_mag < T::epsilon() {
+                return Err(MatrixError::Singular);
+            }
+            let pivot = a.at((k, k)).unwrap().clone();
+            for i in 0..n {
+                *a.at_mut((k, i)).unwrap() = a.at((k, i)).unwrap().clone() / pivot.clone();
+                *inv.at_mut((k, i)).unwrap() = inv.at((k, i)).unwrap().clone() / pivot.clone();
+            }
+            for j in 0..n {
+                if j == k {
+                    continue;
+                }
+                let factor = a.at((j, k)).unwrap().clone();
+                for i in 0..n {
+                    let a_sub = a.at((k, i)).unwrap().clone() * factor.clone();
+                    let cell = a.at_mut((j, i)).unwrap();
+                    *cell = cell.clone() - a_sub;
+                    let inv_sub = inv.at((k, i)).unwrap().clone() * factor.clone();
+                    let inv_cell = inv.at_mut((j, i)).unwrap();
+                    *inv_cell = inv_cell.clone() - inv_sub;
+                }
+            }
+        }
+        Ok(inv)
+    }
+}
+
+// bounded by `Field` rather than `Element` for the same reason as
+// `determinant`/`inverse`: the `T::epsilon()` pivot tolerance is only
+// meaningful for types with exact division.
+impl<T> Matrix<T>
+where
+    T: Clone + PartialEq + PartialOrd + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Field,
+{
+    // solves Ax = b for square coefficient matrix `self` via Gaussian
+    // elimination with partial pivoting, followed by back-substitution;
+    // `b` may carry several right-hand-side columns at once
+    pub fn solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.nrows != self.ncols {
+            return Err(MatrixError::NotSquare(self.shape()));
+        }
+        if b.nrows != self.nrows {
+            return Err(MatrixError::DimMismatch(self.shape(), b.shape()));
+        }
+        let n = self.nrows;
+        let bcols = b.ncols;
+        let mut a = self.clone();
+        let mut rhs = b.clone();
+
+        for k in 0..n {
+            let mut pivot_row = k;
+            let mut pivot_mag = abs(a.at((k, k)).unwrap().clone());
+            for j in (k + 1)..n {
+                let mag = abs(a.at((j, k)).unwrap().clone());
+                if mag > pivot_mag {
+                    pivot_row = j;
+                    pivot_mag = mag;
+                }
+            }
+            if pivot_row != k {
+                a.values.swap(k, pivot_row);
+                rhs.values.swap(k, pivot_row);
+            }
+            if pivot_mag < T::epsilon() {
+                return Err(MatrixError::Singular);
+            }
+            let pivot = a.at((k, k)).unwrap().clone();
+            for j in (k + 1)..n {
+                let factor = a.at((j, k)).unwrap().clone() / pivot.clone();
+                for i in k..n {
+                    let sub = a.at((k, i)).unwrap().clone() * factor.clone();
+                    let cell = a.at_mut((j, i)).unwrap();
+                    *cell = cell.clone() - sub;
+                }
+                for i in 0..bcols {
+                    let sub = rhs.at((k, i)).unwrap().clone() * factor.clone();
+                    let cell = rhs.at_mut((j, i)).unwrap();
+                    *cell = cell.clone() - sub;
+                }
+            }
+        }
+
+        let mut x = Matrix::fill((n, bcols), T::zero());
+        for k in (0..n).rev() {
+            let pivot = a.at((k, k)).unwrap().clone();
+            for i in 0..bcols {
+                let mut sum = rhs.at((k, i)).unwrap().clone();
+                for j in (k + 1)..n {
+                    let term = a.at((k, j)).unwrap().clone() * x.at((j, i)).unwrap().clone();
+                    sum = sum - term;
+                }
+                *x.at_mut((k, i)).unwrap() = sum / pivot.clone();
+            }
+        }
+        Ok(x)
+    }
+}
+
 impl<T: Add<Output = T> + Clone> Add for Matrix<T> {
     type Output = Matrix<T>;
     fn add(mut self, mut rhs: Self) -> Self::Output {
@@ -307,6 +641,74 @@ impl<T: Add<Output = T> + Clone> Add for Matrix<T> {
     }
 }
 
+impl<T: Sub<Output = T> + Clone> Sub for Matrix<T> {
+    type Output = Matrix<T>;
+    fn sub(mut self, mut rhs: Self) -> Self::Output {
+        let mut res = VecDeque::new();
+        if self.shape() != rhs.shape() {
+            if rhs.shape().1 == 1 && self.shape().0 == rhs.shape().0 {
+                let mut rhs_broadcast = Vec::new();
+                for _ in 0..self.shape().1 {
+                    rhs_broadcast.push(rhs.clone().transpose().values.pop_back().unwrap().into());
+                }
+                rhs = Matrix::from_vecs(rhs_broadcast).transpose();
+            } else {
+                panic!("Tried matrix subtraction with shapes that could not broadcast.")
+            }
+        }
+        for _ in 0..self.nrows {
+            for i in 0..self.ncols {
+                if i == 0 {
+                    res.push_back(VecDeque::new());
+                }
+                res.back_mut().unwrap().push_back(
+                    self.values.front_mut().unwrap().pop_front().unwrap()
+                        - rhs.values.front_mut().unwrap().pop_front().unwrap(),
+                );
+                if i == self.ncols - 1 {
+                    self.values.pop_front().unwrap();
+                    rhs.values.pop_front().unwrap();
+                }
+            }
+        }
+        Matrix::new(res)
+    }
+}
+
+impl<T: Div<Output = T> + Clone> Div for Matrix<T> {
+    type Output = Matrix<T>;
+    fn div(mut self, mut rhs: Self) -> Self::Output {
+        let mut res = VecDeque::new();
+        if self.shape() != rhs.shape() {
+            if rhs.shape().1 == 1 && self.shape().0 == rhs.shape().0 {
+                let mut rhs_broadcast = Vec::new();
+                for _ in 0..self.shape().1 {
+                    rhs_broadcast.push(rhs.clone().transpose().values.pop_back().unwrap().into());
+                }
+                rhs = Matrix::from_vecs(rhs_broadcast).transpose();
+            } else {
+                panic!("Tried matrix division with shapes that could not broadcast.")
+            }
+        }
+        for _ in 0..self.nrows {
+            for i in 0..self.ncols {
+                if i == 0 {
+                    res.push_back(VecDeque::new());
+                }
+                res.back_mut().unwrap().push_back(
+                    self.values.front_mut().unwrap().pop_front().unwrap()
+                        / rhs.values.front_mut().unwrap().pop_front().unwrap(),
+                );
+                if i == self.ncols - 1 {
+                    self.values.pop_front().unwrap();
+                    rhs.values.pop_front().unwrap();
+                }
+            }
+        }
+        Matrix::new(res)
+    }
+}
+
 impl<T: Add<Output = T> + AddAssign + Clone> Matrix<T> {
     pub fn dim_sum(&self, dim: usize) -> Matrix<T> {
         let mut res = Vec::new();
@@ -375,6 +777,22 @@ impl<T: Clone + Mul<Output = T>> Mul<T> for Matrix<T> {
     }
 }
 
+impl<T: Clone + Sub<Output = T>> Sub<T> for Matrix<T> {
+    type Output = Matrix<T>;
+    fn sub(self, rhs: T) -> Self::Output {
+        let fill = Matrix::fill(self.shape(), rhs);
+        self - fill
+    }
+}
+
+impl<T: Clone + Div<Output = T>> Div<T> for Matrix<T> {
+    type Output = Matrix<T>;
+    fn div(self, rhs: T) -> Self::Output {
+        let fill = Matrix::fill(self.shape(), rhs);
+        self / fill
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -434,6 +852,81 @@ mod tests {
         assert_eq!(a + b, ans);
     }
 
+    #[test]
+    fn sub_overflow() {
+        let a = Matrix::new(VecDeque::from([
+            VecDeque::from([1, 2, 3]),
+            VecDeque::from([4, 5, 6]),
+        ]));
+        let b = Matrix::new(VecDeque::from([
+            VecDeque::from([1, 1, 1]),
+            VecDeque::from([2, 2, 2]),
+        ]));
+        let ans = Matrix::new(VecDeque::from([
+            VecDeque::from([0, 1, 2]),
+            VecDeque::from([2, 3, 4]),
+        ]));
+        assert_eq!(a - b, ans);
+    }
+
+    #[test]
+    fn sub_broadcast() {
+        let a = Matrix::new(VecDeque::from([
+            VecDeque::from([1, 2, 3]),
+            VecDeque::from([4, 5, 6]),
+        ]));
+        let b = Matrix::new(VecDeque::from([VecDeque::from([1]), VecDeque::from([4])]));
+        let ans = Matrix::new(VecDeque::from([
+            VecDeque::from([0, 1, 2]),
+            VecDeque::from([0, 1, 2]),
+        ]));
+        assert_eq!(a - b, ans);
+    }
+
+    #[test]
+    fn div_overflow() {
+        let a = Matrix::new(VecDeque::from([
+            VecDeque::from([2.0, 4.0, 6.0]),
+            VecDeque::from([8.0, 10.0, 12.0]),
+        ]));
+        let b = Matrix::new(VecDeque::from([
+            VecDeque::from([2.0, 2.0, 2.0]),
+            VecDeque::from([2.0, 2.0, 2.0]),
+        ]));
+        let ans = Matrix::new(VecDeque::from([
+            VecDeque::from([1.0, 2.0, 3.0]),
+            VecDeque::from([4.0, 5.0, 6.0]),
+        ]));
+        assert_eq!(a / b, ans);
+    }
+
+    #[test]
+    fn div_broadcast() {
+        let a = Matrix::new(VecDeque::from([
+            VecDeque::from([2.0, 4.0, 6.0]),
+            VecDeque::from([8.0, 10.0, 12.0]),
+        ]));
+        let b = Matrix::new(VecDeque::from([VecDeque::from([2.0]), VecDeque::from([4.0])]));
+        let ans = Matrix::new(VecDeque::from([
+            VecDeque::from([1.0, 2.0, 3.0]),
+            VecDeque::from([2.0, 2.5, 3.0]),
+        ]));
+        assert_eq!(a / b, ans);
+    }
+
+    #[test]
+    fn scalar_div() {
+        let a = Matrix::new(VecDeque::from([
+            VecDeque::from([2.0, 4.0]),
+            VecDeque::from([6.0, 8.0]),
+        ]));
+        let ans = Matrix::new(VecDeque::from([
+            VecDeque::from([1.0, 2.0]),
+            VecDeque::from([3.0, 4.0]),
+        ]));
+        assert_eq!(a / 2.0, ans);
+    }
+
     #[test]
     fn columnwise_sum() {
         let a = Matrix::new(VecDeque::from([
@@ -487,6 +980,24 @@ mod tests {
         assert_eq!((t3 * 10000.0).round(), (a3 * 10000.0).round());
     }
 
+    #[test]
+    fn softmax_large_inputs_stay_finite() {
+        impl Max for f64 {
+            fn max(self, other: Self) -> Self {
+                f64::max(self, other)
+            }
+        }
+        let a = Matrix::new(VecDeque::from([VecDeque::from([1000.0, 1001.0])]));
+        let sm = a.softmax(1);
+        let t1: f64 = *sm.at((0, 0)).unwrap();
+        let t2: f64 = *sm.at((0, 1)).unwrap();
+        assert!(t1.is_finite() && t2.is_finite());
+        let a1: f64 = 0.2689;
+        let a2: f64 = 0.7311;
+        assert_eq!((t1 * 10000.0).round(), (a1 * 10000.0).round());
+        assert_eq!((t2 * 10000.0).round(), (a2 * 10000.0).round());
+    }
+
     #[test]
     fn mul_overflow() {
         let a = Matrix::new(VecDeque::from([
@@ -557,6 +1068,149 @@ mod tests {
         assert_eq!(format!("{a}"), "[[1, 2, 3]\n [4, 5, 6]]");
     }
 
+    #[test]
+    fn minor() {
+        let a = Matrix::new(VecDeque::from([
+            VecDeque::from([1.0, 2.0, 3.0]),
+            VecDeque::from([4.0, 5.0, 6.0]),
+            VecDeque::from([7.0, 8.0, 9.0]),
+        ]));
+        let ans = Matrix::new(VecDeque::from([
+            VecDeque::from([1.0, 3.0]),
+            VecDeque::from([7.0, 9.0]),
+        ]));
+        assert_eq!(a.minor(1, 1), ans);
+    }
+
+    #[test]
+    #[should_panic]
+    fn minor_out_of_bounds() {
+        let a = Matrix::new(VecDeque::from([
+            VecDeque::from([1.0, 2.0, 3.0]),
+            VecDeque::from([4.0, 5.0, 6.0]),
+            VecDeque::from([7.0, 8.0, 9.0]),
+        ]));
+        let _ = a.minor(10, 10);
+    }
+
+    #[test]
+    fn determinant() {
+        let a = Matrix::new(VecDeque::from([
+            VecDeque::from([1.0, 2.0]),
+            VecDeque::from([3.0, 4.0]),
+        ]));
+        assert_eq!(a.determinant(), Ok(-2.0));
+
+        let non_square = Matrix::new(VecDeque::from([VecDeque::from([1.0, 2.0, 3.0])]));
+        assert_eq!(
+            non_square.determinant(),
+            Err(MatrixError::NotSquare((1, 3)))
+        );
+    }
+
+    #[test]
+    fn cofactor() {
+        let a = Matrix::new(VecDeque::from([
+            VecDeque::from([1.0, 2.0, 3.0]),
+            VecDeque::from([4.0, 5.0, 6.0]),
+            VecDeque::from([7.0, 8.0, 10.0]),
+        ]));
+        let c00 = a.cofactor(0, 0).unwrap();
+        assert!((c00 - (5.0 * 10.0 - 6.0 * 8.0)).abs() < 1e-9);
+        let c01 = a.cofactor(0, 1).unwrap();
+        assert!((c01 - -(4.0 * 10.0 - 6.0 * 7.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn inverse() {
+        let a = Matrix::new(VecDeque::from([
+            VecDeque::from([4.0, 7.0]),
+            VecDeque::from([2.0, 6.0]),
+        ]));
+        let inv = a.inverse().unwrap();
+        let identity = a.matmul(&inv).unwrap();
+        for j in 0..2 {
+            for i in 0..2 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((identity.at((j, i)).unwrap() - expected).abs() < 1e-9);
+            }
+        }
+
+        let singular = Matrix::new(VecDeque::from([
+            VecDeque::from([1.0, 2.0]),
+            VecDeque::from([2.0, 4.0]),
+        ]));
+        assert_eq!(singular.inverse(), Err(MatrixError::Singular));
+    }
+
+    #[test]
+    fn solve() {
+        let a = Matrix::new(VecDeque::from([
+            VecDeque::from([2.0, 1.0]),
+            VecDeque::from([1.0, 3.0]),
+        ]));
+        let b = Matrix::new(VecDeque::from([VecDeque::from([5.0]), VecDeque::from([10.0])]));
+        let x = a.solve(&b).unwrap();
+        assert!((x.at((0, 0)).unwrap() - 1.0).abs() < 1e-9);
+        assert!((x.at((1, 0)).unwrap() - 3.0).abs() < 1e-9);
+
+        let singular = Matrix::new(VecDeque::from([
+            VecDeque::from([1.0, 2.0]),
+            VecDeque::from([2.0, 4.0]),
+        ]));
+        assert_eq!(singular.solve(&b), Err(MatrixError::Singular));
+    }
+
+    #[test]
+    fn index() {
+        let mut a = Matrix::new(VecDeque::from([
+            VecDeque::from([1, 2, 3]),
+            VecDeque::from([4, 5, 6]),
+        ]));
+        assert_eq!(a[(1, 2)], 6);
+        a[(1, 2)] = 60;
+        assert_eq!(a[(1, 2)], 60);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let a = Matrix::new(VecDeque::from([VecDeque::from([1, 2, 3])]));
+        let _ = a[(5, 5)];
+    }
+
+    #[test]
+    fn iter_non_consuming() {
+        let a = Matrix::new(VecDeque::from([
+            VecDeque::from([1, 2]),
+            VecDeque::from([3, 4]),
+        ]));
+        assert_eq!(a.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        // the matrix is still usable afterwards
+        assert_eq!(a.shape(), (2, 2));
+    }
+
+    #[test]
+    fn rows_and_cols() {
+        let a = Matrix::new(VecDeque::from([
+            VecDeque::from([1, 2, 3]),
+            VecDeque::from([4, 5, 6]),
+        ]));
+        let rows: Vec<Vec<i32>> = a.rows().map(|row| row.copied().collect()).collect();
+        assert_eq!(rows, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        let cols: Vec<Vec<i32>> = a.cols().map(|col| col.copied().collect()).collect();
+        assert_eq!(cols, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    }
+
+    #[test]
+    fn indexed() {
+        let a = Matrix::new(VecDeque::from([VecDeque::from([1, 2])]));
+        let entries: Vec<((usize, usize), i32)> =
+            a.indexed().map(|(idx, el)| (idx, *el)).collect();
+        assert_eq!(entries, vec![((0, 0), 1), ((0, 1), 2)]);
+    }
+
     #[test]
     fn iterate() {
         let a = Matrix::new(VecDeque::from([
@@ -19,6 +19,51 @@ pub fn load(filename: &str) -> Result<Matrix<f64>, Box<dyn std::error::Error>> {
     read(npy)
 }
 
+// Serialize a Matrix<f64> to a little-endian .npy file with the correct 2-D shape header
+pub fn save(matrix: &Matrix<f64>, filename: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let file = File::create(filename)?;
+    write(matrix, file)
+}
+
+// Write a Matrix<f64> as npy bytes into any writer, shared by `save` and `save_npz`
+fn write<W: std::io::Write>(
+    matrix: &Matrix<f64>,
+    writer: W,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (nrows, ncols) = matrix.shape();
+    let mut npy_writer = npyz::WriteOptions::new()
+        .default_dtype()
+        .shape(&[nrows as u64, ncols as u64])
+        .writer(writer)
+        .begin_nd()?;
+    npy_writer.extend(matrix.iter().copied())?;
+    npy_writer.finish()?;
+    Ok(())
+}
+
+// Bundle several named matrices into a zip archive, matching the a*/b*/c* naming
+// that load_tests already parses
+pub fn save_npz(
+    filename: &str,
+    a: &[Matrix<f64>],
+    b: &[Matrix<f64>],
+    c: &[Matrix<f64>],
+) -> Result<(), Box<dyn std::error::Error>> {
+    let file = File::create(filename)?;
+    let mut archive = zip::ZipWriter::new(file);
+    let options = zip::write::FileOptions::default();
+
+    for (prefix, matrices) in [("a", a), ("b", b), ("c", c)] {
+        for (i, matrix) in matrices.iter().enumerate() {
+            archive.start_file(format!("{prefix}{i}.npy"), options)?;
+            write(matrix, &mut archive)?;
+        }
+    }
+
+    archive.finish()?;
+    Ok(())
+}
+
 // Convert an npy input stream into a Matrix
 fn read<R: std::io::Read>(npy: NpyFile<R>) -> Result<Matrix<f64>, Box<dyn std::error::Error>> {
     let width = npy.shape()[1];
@@ -138,3 +183,21 @@ pub fn run_tests() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn save_load_round_trip() {
+        let matrix = Matrix::from_vecs(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+        let path = std::env::temp_dir().join(format!("matrix_library_test_{}.npy", std::process::id()));
+        let filename = path.to_str().unwrap();
+
+        save(&matrix, filename).unwrap();
+        let loaded = load(filename).unwrap();
+        std::fs::remove_file(filename).unwrap();
+
+        assert_eq!(loaded, matrix);
+    }
+}
@@ -5,3 +5,7 @@ pub trait Exp {
 pub trait Pow<T = Self> {
     fn pow(self, exp: T) -> Self;
 }
+
+pub trait Max {
+    fn max(self, other: Self) -> Self;
+}